@@ -0,0 +1,845 @@
+use crate::card::{Card, CardId, Cost};
+use crate::game_logic::{find_card, find_noble, WINNING_SCORE};
+use crate::gem_type::GemType;
+use crate::nobles::{Noble, NobleId};
+use crate::player::Player;
+use crate::token::{TokenDelta, Tokens};
+use crate::JSONable;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A single recorded game action. Arena actions never mutate state
+/// directly; they build a `GameEvent`, apply it, and append it to the
+/// game's log, so the log is always a complete, ordered transcript that
+/// `replay` can reconstruct state from and `undo`/`redo` can step through.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GameEvent {
+    TakeTokens {
+        player: usize,
+        tokens: Tokens,
+    },
+    Reserve {
+        player: usize,
+        card: CardId,
+        /// Where `card` sat in `board` at the time it was reserved, so
+        /// `undo` can put it back in the same slot instead of tacking it
+        /// onto the end.
+        board_index: usize,
+        /// The board card drawn from the deck to fill the reserved card's
+        /// spot, if the deck still had one.
+        drawn_replacement: Option<CardId>,
+    },
+    BlindReserve {
+        player: usize,
+        card: CardId,
+    },
+    Purchase {
+        player: usize,
+        card: CardId,
+        payment: Tokens,
+        /// The player's point total immediately before this purchase.
+        /// `undo` subtracts the card's points and, in debug builds, checks
+        /// that the result equals this total.
+        points_before: u8,
+        was_reserved: bool,
+        was_blind_reserved: bool,
+        /// Where `card` sat in `board` at the time it was purchased, or
+        /// `None` if it was purchased out of the player's hand instead of
+        /// off the board. Lets `undo` restore the card to its original slot.
+        board_index: Option<usize>,
+        /// The board card drawn from the deck to fill this one's spot, if
+        /// the card came off the board and the deck still had one.
+        drawn_replacement: Option<CardId>,
+    },
+    ClaimNoble {
+        player: usize,
+        noble: NobleId,
+    },
+    Discard {
+        player: usize,
+        tokens: Tokens,
+    },
+}
+
+impl GameEvent {
+    pub fn player(&self) -> usize {
+        match self {
+            GameEvent::TakeTokens { player, .. }
+            | GameEvent::Reserve { player, .. }
+            | GameEvent::BlindReserve { player, .. }
+            | GameEvent::Purchase { player, .. }
+            | GameEvent::ClaimNoble { player, .. }
+            | GameEvent::Discard { player, .. } => *player,
+        }
+    }
+}
+
+/// The player count, bank sizes, win condition, and noble selection a game
+/// is played under. An `Arena` stores the `GameConfig` it was built from so
+/// a `replay` knows the exact ruleset the transcript ran under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameConfig {
+    pub num_players: usize,
+    pub starting_bank_per_color: u8,
+    pub gold_count: u8,
+    pub winning_score: u8,
+    pub nobles: Vec<NobleId>,
+    /// Seed used to shuffle the initial board/deck deal, if any. `None`
+    /// deals cards in a fixed `CardId` order. This has to live here rather
+    /// than being applied only transiently by `new_seeded`: `replay`
+    /// rebuilds the starting deal from `config` alone, so the seed must be
+    /// part of it or a shuffled game replays into a deck it never had.
+    pub shuffle_seed: Option<u64>,
+}
+
+impl GameConfig {
+    /// The standard ruleset for `num_players`: bank size scaled the usual
+    /// way (4/5/7 tokens per color for 2/3/4+ players), 5 gold, a 15-point
+    /// win condition, and the first `num_players + 1` nobles.
+    pub fn standard(num_players: usize) -> GameConfig {
+        let starting_bank_per_color = match num_players {
+            2 => 4,
+            3 => 5,
+            _ => 7,
+        };
+        // Only `Noble::all().len()` nobles exist; clamp rather than slicing
+        // out of bounds for a player count the standard ruleset can't
+        // actually support. `validate` is what turns this into a proper
+        // `NotEnoughNobles` error instead of a panic.
+        let noble_count = (num_players + 1).min(Noble::all().len());
+        GameConfig {
+            num_players,
+            starting_bank_per_color,
+            gold_count: 5,
+            winning_score: WINNING_SCORE,
+            nobles: Noble::all()[..noble_count]
+                .iter()
+                .map(Noble::id)
+                .collect(),
+            shuffle_seed: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GameConfigError> {
+        if self.num_players < 2 {
+            return Err(GameConfigError::TooFewPlayers(self.num_players));
+        }
+        if self.nobles.len() < self.num_players + 1 {
+            return Err(GameConfigError::NotEnoughNobles {
+                have: self.nobles.len(),
+                need: self.num_players + 1,
+            });
+        }
+        // Bank sizes are unsigned, so they can never go negative; the real
+        // failure mode is a bank too small to play with at all.
+        if self.starting_bank_per_color == 0 {
+            return Err(GameConfigError::EmptyBank);
+        }
+        validate_noble_ids(&self.nobles)?;
+        Ok(())
+    }
+}
+
+/// Checks that every id in `ids` refers to a real noble, so callers that
+/// take `NobleId`s from outside the crate (a deserialized `GameConfig`, a
+/// `swap_nobles` call) fail with a `GameConfigError` instead of panicking
+/// later when `find_noble` can't find it.
+fn validate_noble_ids(ids: &[NobleId]) -> Result<(), GameConfigError> {
+    for &id in ids {
+        if !Noble::all().iter().any(|noble| noble.id() == id) {
+            return Err(GameConfigError::UnknownNoble(id));
+        }
+    }
+    Ok(())
+}
+
+/// Why a `GameConfig` was rejected, or a setup change couldn't be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameConfigError {
+    TooFewPlayers(usize),
+    NotEnoughNobles { have: usize, need: usize },
+    EmptyBank,
+    GameAlreadyStarted,
+    UnknownNoble(NobleId),
+}
+
+impl fmt::Display for GameConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameConfigError::TooFewPlayers(n) => write!(f, "need at least 2 players, got {n}"),
+            GameConfigError::NotEnoughNobles { have, need } => {
+                write!(f, "need at least {need} nobles for this many players, got {have}")
+            }
+            GameConfigError::EmptyBank => {
+                write!(f, "starting bank per color must be greater than zero")
+            }
+            GameConfigError::GameAlreadyStarted => {
+                write!(f, "cannot change setup after the first move has been made")
+            }
+            GameConfigError::UnknownNoble(id) => {
+                write!(f, "{id:?} does not refer to a known noble")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameConfigError {}
+
+/// The change to one player's public state caused by a single turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerDelta {
+    pub gems: TokenDelta,
+    /// Developments gained this turn, by color (usually zero or one color).
+    pub new_developments: Cost,
+    pub point_gain: i8,
+}
+
+/// A compact summary of one completed turn: who acted, what `GameEvent`
+/// they caused, and how it changed every player's and the bank's public
+/// state. A client that already has a full snapshot (e.g. from `to_view`)
+/// can apply a stream of these instead of re-fetching the whole game state
+/// after every move.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnSummary {
+    pub player: usize,
+    pub event: GameEvent,
+    /// Indexed the same way as `Arena::players`.
+    pub player_deltas: Vec<PlayerDelta>,
+    pub bank_delta: TokenDelta,
+}
+
+/// The full state of one game: players, bank, nobles in play, and the
+/// append-only log of everything that has happened so far.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Arena {
+    players: Vec<Player>,
+    bank: Tokens,
+    nobles: Vec<Noble>,
+    /// Face-up cards available to reserve or purchase.
+    board: Vec<CardId>,
+    /// Cards not yet drawn onto the board, in draw order.
+    deck: Vec<CardId>,
+    current_player: usize,
+    log: Vec<GameEvent>,
+    redo_stack: Vec<GameEvent>,
+    config: GameConfig,
+}
+
+impl JSONable for Arena {}
+
+/// How many cards are dealt face-up onto the board at once.
+const BOARD_SIZE: usize = 8;
+
+impl Arena {
+    /// Builds a fresh game under the standard ruleset for `num_players`.
+    /// Panics if no standard ruleset exists for that many players: the
+    /// standard ruleset needs `num_players + 1` nobles, and only
+    /// `Noble::all().len()` of them exist.
+    pub fn new(num_players: usize) -> Arena {
+        Arena::from_config(GameConfig::standard(num_players))
+            .expect("no standard ruleset for this player count")
+    }
+
+    /// Builds a fresh game under `config`, failing if it describes an
+    /// unplayable setup (e.g. too few nobles for the player count).
+    pub fn from_config(config: GameConfig) -> Result<Arena, GameConfigError> {
+        config.validate()?;
+
+        let mut bank = Tokens::empty();
+        for color in GemType::all() {
+            bank[color] = config.starting_bank_per_color;
+        }
+        bank.gold = config.gold_count;
+
+        let nobles = config.nobles.iter().map(|&id| *find_noble(id)).collect();
+        let mut all_cards: Vec<CardId> = Card::all().iter().map(Card::id).collect();
+        if let Some(seed) = config.shuffle_seed {
+            crate::rng::Rng::new(seed).shuffle(&mut all_cards);
+        }
+        let board_size = BOARD_SIZE.min(all_cards.len());
+
+        Ok(Arena {
+            players: (0..config.num_players).map(|_| Player::new()).collect(),
+            bank,
+            nobles,
+            board: all_cards[..board_size].to_vec(),
+            deck: all_cards[board_size..].to_vec(),
+            current_player: 0,
+            log: Vec::new(),
+            redo_stack: Vec::new(),
+            config,
+        })
+    }
+
+    /// Builds a fresh game exactly like `new`, but with the board and deck
+    /// shuffled using `seed` so repeated games with the same seed deal the
+    /// same cards. The seed is kept on the resulting `config`, so `replay`
+    /// of this game's log deals the identical board/deck before applying
+    /// any events.
+    pub fn new_seeded(num_players: usize, seed: u64) -> Arena {
+        let mut config = GameConfig::standard(num_players);
+        config.shuffle_seed = Some(seed);
+        Arena::from_config(config).expect("no standard ruleset for this player count")
+    }
+
+    /// Rebuilds an `Arena` from scratch by replaying `events` against a
+    /// fresh game built from `config` — the same ruleset the transcript
+    /// originally ran under.
+    pub fn replay(config: GameConfig, events: &[GameEvent]) -> Result<Arena, GameConfigError> {
+        let mut arena = Arena::from_config(config)?;
+        for event in events {
+            arena.apply(event);
+            arena.log.push(event.clone());
+        }
+        Ok(arena)
+    }
+
+    /// The ruleset this game was built from.
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Swaps which nobles are in play. Only allowed before the first move
+    /// of the game, mirroring how a player can reconfigure the available
+    /// card pool during setup.
+    pub fn swap_nobles(&mut self, noble_ids: Vec<NobleId>) -> Result<(), GameConfigError> {
+        if !self.log.is_empty() {
+            return Err(GameConfigError::GameAlreadyStarted);
+        }
+        if noble_ids.len() < self.players.len() + 1 {
+            return Err(GameConfigError::NotEnoughNobles {
+                have: noble_ids.len(),
+                need: self.players.len() + 1,
+            });
+        }
+        validate_noble_ids(&noble_ids)?;
+        self.nobles = noble_ids.iter().map(|&id| *find_noble(id)).collect();
+        self.config.nobles = noble_ids;
+        Ok(())
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn bank(&self) -> &Tokens {
+        &self.bank
+    }
+
+    pub fn nobles(&self) -> &[Noble] {
+        &self.nobles
+    }
+
+    /// The face-up cards currently available to reserve or purchase.
+    pub fn board(&self) -> &[CardId] {
+        &self.board
+    }
+
+    /// The cards not yet drawn onto the board, in draw order.
+    pub fn deck(&self) -> &[CardId] {
+        &self.deck
+    }
+
+    pub fn current_player(&self) -> usize {
+        self.current_player
+    }
+
+    /// The full transcript of events applied so far, in order.
+    pub fn log(&self) -> &[GameEvent] {
+        &self.log
+    }
+
+    fn advance_turn(&mut self) {
+        self.current_player = (self.current_player + 1) % self.players.len();
+    }
+
+    fn retreat_turn(&mut self) {
+        self.current_player = (self.current_player + self.players.len() - 1) % self.players.len();
+    }
+
+    /// Mutates state forward to reflect `event`, without touching the log.
+    /// Shared by the action methods below, `replay`, and `redo` so there is
+    /// exactly one place that knows how to apply a `GameEvent`.
+    fn apply(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::TakeTokens { player, tokens } => {
+                self.bank -= *tokens;
+                self.players[*player].add_gems(*tokens);
+                self.advance_turn();
+            }
+            GameEvent::Reserve {
+                player,
+                card,
+                board_index,
+                drawn_replacement,
+            } => {
+                self.players[*player].reserve_card(*card);
+                self.board.remove(*board_index);
+                if let Some(replacement) = drawn_replacement {
+                    self.deck.retain(|c| c != replacement);
+                    self.board.insert(*board_index, *replacement);
+                }
+                self.advance_turn();
+            }
+            GameEvent::BlindReserve { player, card } => {
+                self.players[*player].blind_reserve_card(*card);
+                self.deck.retain(|c| c != card);
+                self.advance_turn();
+            }
+            GameEvent::Purchase {
+                player,
+                card,
+                payment,
+                board_index,
+                drawn_replacement,
+                ..
+            } => {
+                let card_data = find_card(*card);
+                self.players[*player].purchase_card(card_data, payment);
+                self.bank += *payment;
+                if let Some(index) = board_index {
+                    self.board.remove(*index);
+                    if let Some(replacement) = drawn_replacement {
+                        self.deck.retain(|c| c != replacement);
+                        self.board.insert(*index, *replacement);
+                    }
+                }
+                self.advance_turn();
+            }
+            GameEvent::ClaimNoble { player, noble } => {
+                self.players[*player].add_noble_points();
+                self.nobles.retain(|n| n.id() != *noble);
+            }
+            GameEvent::Discard { player, tokens } => {
+                self.players[*player].remove_gems(*tokens);
+                self.bank += *tokens;
+            }
+        }
+    }
+
+    /// Mutates state backward to undo `event`, the inverse of `apply`.
+    fn reverse(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::TakeTokens { player, tokens } => {
+                self.players[*player].remove_gems(*tokens);
+                self.bank += *tokens;
+                self.retreat_turn();
+            }
+            GameEvent::Reserve {
+                player,
+                card,
+                board_index,
+                drawn_replacement,
+            } => {
+                self.players[*player].unreserve_card(*card);
+                if drawn_replacement.is_some() {
+                    self.board.remove(*board_index);
+                }
+                if let Some(replacement) = drawn_replacement {
+                    self.deck.insert(0, *replacement);
+                }
+                self.board.insert(*board_index, *card);
+                self.retreat_turn();
+            }
+            GameEvent::BlindReserve { player, card } => {
+                self.players[*player].unreserve_card(*card);
+                self.deck.insert(0, *card);
+                self.retreat_turn();
+            }
+            GameEvent::Purchase {
+                player,
+                card,
+                payment,
+                points_before,
+                was_reserved,
+                was_blind_reserved,
+                board_index,
+                drawn_replacement,
+            } => {
+                let card_data = find_card(*card);
+                self.players[*player].unpurchase_card(
+                    card_data,
+                    payment,
+                    *was_reserved,
+                    *was_blind_reserved,
+                );
+                debug_assert_eq!(self.players[*player].points(), *points_before);
+                self.bank -= *payment;
+                if let Some(index) = board_index {
+                    if drawn_replacement.is_some() {
+                        self.board.remove(*index);
+                    }
+                    if let Some(replacement) = drawn_replacement {
+                        self.deck.insert(0, *replacement);
+                    }
+                    self.board.insert(*index, *card);
+                }
+                self.retreat_turn();
+            }
+            GameEvent::ClaimNoble { player, noble } => {
+                self.players[*player].remove_noble_points();
+                self.nobles.push(*find_noble(*noble));
+            }
+            GameEvent::Discard { player, tokens } => {
+                self.players[*player].add_gems(*tokens);
+                self.bank -= *tokens;
+            }
+        }
+    }
+
+    /// Applies `event`, appends it to the log (clearing the redo stack),
+    /// and reports the resulting `TurnSummary` by diffing public state
+    /// from just before the event to just after. Shared by every action
+    /// method below so the summary can never drift from what `apply`
+    /// actually did.
+    fn record_turn(&mut self, event: GameEvent) -> TurnSummary {
+        let bank_before = self.bank;
+        let gems_before: Vec<Tokens> = self.players.iter().map(|p| *p.gems()).collect();
+        let developments_before: Vec<Tokens> =
+            self.players.iter().map(|p| *p.developments()).collect();
+        let points_before: Vec<u8> = self.players.iter().map(Player::points).collect();
+
+        self.apply(&event);
+        self.log.push(event.clone());
+        self.redo_stack.clear();
+
+        let player_deltas = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(i, p)| PlayerDelta {
+                gems: TokenDelta::from_before_after(&gems_before[i], p.gems()),
+                new_developments: Cost::from_tokens(&(*p.developments() - developments_before[i])),
+                point_gain: p.points() as i8 - points_before[i] as i8,
+            })
+            .collect();
+
+        TurnSummary {
+            player: event.player(),
+            event,
+            player_deltas,
+            bank_delta: TokenDelta::from_before_after(&bank_before, &self.bank),
+        }
+    }
+
+    pub fn take_tokens(&mut self, tokens: Tokens) -> TurnSummary {
+        let event = GameEvent::TakeTokens {
+            player: self.current_player,
+            tokens,
+        };
+        self.record_turn(event)
+    }
+
+    /// Reserves a face-up board card.
+    pub fn reserve_card(&mut self, card_id: CardId) -> TurnSummary {
+        let board_index = self
+            .board
+            .iter()
+            .position(|&c| c == card_id)
+            .expect("card id should be on the board");
+        let drawn_replacement = self.deck.first().copied();
+        let event = GameEvent::Reserve {
+            player: self.current_player,
+            card: card_id,
+            board_index,
+            drawn_replacement,
+        };
+        self.record_turn(event)
+    }
+
+    /// Reserves the top card of the deck, unseen. The drawn card's id can be
+    /// read back off the returned summary's event (the caller, e.g. the
+    /// current player, learns it; opponents should not).
+    pub fn blind_reserve_card(&mut self) -> TurnSummary {
+        let card_id = *self.deck.first().expect("deck should not be empty");
+        let event = GameEvent::BlindReserve {
+            player: self.current_player,
+            card: card_id,
+        };
+        self.record_turn(event)
+    }
+
+    pub fn purchase_card(&mut self, card_id: CardId, payment: Tokens) -> TurnSummary {
+        let player = self.current_player;
+        let points_before = self.players[player].points();
+        let was_reserved = self.players[player].all_reserved().contains(&card_id);
+        let was_blind_reserved = self.players[player].blind_reserved().contains(&card_id);
+        let board_index = self.board.iter().position(|&c| c == card_id);
+        let drawn_replacement = if board_index.is_some() {
+            self.deck.first().copied()
+        } else {
+            None
+        };
+        let event = GameEvent::Purchase {
+            player,
+            card: card_id,
+            payment,
+            points_before,
+            was_reserved,
+            was_blind_reserved,
+            board_index,
+            drawn_replacement,
+        };
+        self.record_turn(event)
+    }
+
+    /// Awards `noble_id` to `player`. Takes the player explicitly rather
+    /// than using `current_player`: a noble is claimed as a consequence of
+    /// the move the acting player just made (typically a `Purchase`), by
+    /// which point `current_player` has already advanced to the next
+    /// player's turn.
+    pub fn claim_noble(&mut self, player: usize, noble_id: NobleId) -> TurnSummary {
+        let event = GameEvent::ClaimNoble {
+            player,
+            noble: noble_id,
+        };
+        self.record_turn(event)
+    }
+
+    pub fn discard(&mut self, tokens: Tokens) -> TurnSummary {
+        let event = GameEvent::Discard {
+            player: self.current_player,
+            tokens,
+        };
+        self.record_turn(event)
+    }
+
+    /// Reverses the last recorded event, moving it onto the redo stack.
+    /// Panics if there is nothing left to undo.
+    pub fn undo(&mut self) {
+        let event = self.log.pop().expect("no events to undo");
+        self.reverse(&event);
+        self.redo_stack.push(event);
+    }
+
+    /// Re-applies the most recently undone event. Panics if there is
+    /// nothing left to redo; any new action clears the redo stack, exactly
+    /// like a standard editor undo/redo history.
+    pub fn redo(&mut self) {
+        let event = self.redo_stack.pop().expect("no events to redo");
+        self.apply(&event);
+        self.log.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(ruby: u8, emerald: u8, sapphire: u8, diamond: u8, onyx: u8, gold: u8) -> Tokens {
+        Tokens {
+            ruby,
+            emerald,
+            sapphire,
+            diamond,
+            onyx,
+            gold,
+        }
+    }
+
+    #[test]
+    fn test_seeded_replay_round_trip() {
+        let mut arena = Arena::new_seeded(2, 7);
+        arena.take_tokens(tokens(1, 1, 1, 0, 0, 0));
+        arena.reserve_card(arena.board()[0]);
+        arena.take_tokens(tokens(1, 0, 0, 1, 1, 0));
+        arena.blind_reserve_card();
+        arena.take_tokens(tokens(0, 2, 0, 0, 0, 0));
+
+        let replayed = Arena::replay(arena.config().clone(), arena.log()).unwrap();
+
+        assert_eq!(replayed.board(), arena.board());
+        assert_eq!(replayed.deck(), arena.deck());
+        assert_eq!(replayed.bank(), arena.bank());
+        assert_eq!(replayed.current_player(), arena.current_player());
+        for (replayed_player, player) in replayed.players().iter().zip(arena.players().iter()) {
+            assert_eq!(replayed_player.gems(), player.gems());
+            assert_eq!(replayed_player.developments(), player.developments());
+            assert_eq!(replayed_player.all_reserved(), player.all_reserved());
+        }
+    }
+
+    #[test]
+    fn test_undo_restores_previous_state() {
+        let mut arena = Arena::new(2);
+        let board_before = arena.board().to_vec();
+        let bank_before = *arena.bank();
+
+        arena.take_tokens(tokens(1, 1, 1, 0, 0, 0));
+        assert_ne!(arena.bank(), &bank_before);
+
+        arena.undo();
+        assert_eq!(arena.board(), board_before);
+        assert_eq!(arena.bank(), &bank_before);
+        assert_eq!(arena.current_player(), 0);
+    }
+
+    #[test]
+    fn test_redo_reapplies_undone_event() {
+        let mut arena = Arena::new(2);
+        let summary = arena.take_tokens(tokens(1, 1, 1, 0, 0, 0));
+        let bank_after_take = *arena.bank();
+
+        arena.undo();
+        arena.redo();
+
+        assert_eq!(arena.bank(), &bank_after_take);
+        assert_eq!(summary.bank_delta.ruby, -1);
+    }
+
+    #[test]
+    fn test_claim_noble_removes_it_from_play_and_undo_restores_it() {
+        let mut arena = Arena::new(2);
+        let noble_id = arena.nobles()[0].id();
+        let nobles_before = arena.nobles().len();
+
+        arena.claim_noble(0, noble_id);
+        assert_eq!(arena.players()[0].noble_points(), 3);
+        assert_eq!(arena.nobles().len(), nobles_before - 1);
+        assert!(!arena.nobles().iter().any(|n| n.id() == noble_id));
+
+        arena.undo();
+        assert_eq!(arena.players()[0].noble_points(), 0);
+        assert_eq!(arena.nobles().len(), nobles_before);
+        assert!(arena.nobles().iter().any(|n| n.id() == noble_id));
+    }
+
+    #[test]
+    fn test_game_config_validate_rejects_too_few_players() {
+        let mut config = GameConfig::standard(2);
+        config.num_players = 1;
+        assert_eq!(config.validate(), Err(GameConfigError::TooFewPlayers(1)));
+    }
+
+    #[test]
+    fn test_game_config_validate_rejects_not_enough_nobles() {
+        let mut config = GameConfig::standard(2);
+        config.nobles.pop();
+        assert_eq!(
+            config.validate(),
+            Err(GameConfigError::NotEnoughNobles { have: 2, need: 3 })
+        );
+    }
+
+    #[test]
+    fn test_game_config_validate_rejects_empty_bank() {
+        let mut config = GameConfig::standard(2);
+        config.starting_bank_per_color = 0;
+        assert_eq!(config.validate(), Err(GameConfigError::EmptyBank));
+    }
+
+    #[test]
+    fn test_swap_nobles_rejects_after_game_started() {
+        let mut arena = Arena::new(2);
+        arena.take_tokens(tokens(1, 1, 1, 0, 0, 0));
+        let noble_ids: Vec<NobleId> = arena.nobles().iter().map(Noble::id).collect();
+        assert_eq!(
+            arena.swap_nobles(noble_ids),
+            Err(GameConfigError::GameAlreadyStarted)
+        );
+    }
+
+    #[test]
+    fn test_swap_nobles_rejects_too_few_nobles() {
+        let mut arena = Arena::new(2);
+        assert_eq!(
+            arena.swap_nobles(vec![]),
+            Err(GameConfigError::NotEnoughNobles { have: 0, need: 3 })
+        );
+    }
+
+    #[test]
+    fn test_swap_nobles_replaces_nobles_before_first_move() {
+        let mut arena = Arena::new(3);
+        let new_ids: Vec<NobleId> = Noble::all()[..4].iter().map(Noble::id).collect();
+
+        arena.swap_nobles(new_ids.clone()).unwrap();
+
+        let current_ids: Vec<NobleId> = arena.nobles().iter().map(Noble::id).collect();
+        assert_eq!(current_ids, new_ids);
+        assert_eq!(arena.config().nobles, new_ids);
+    }
+
+    #[test]
+    fn test_purchase_turn_summary_reports_point_gain_and_new_developments() {
+        let mut arena = Arena::new(2);
+        let card = Card::all()[3];
+
+        arena.take_tokens(tokens(0, 0, 0, 4, 0, 0));
+        arena.take_tokens(tokens(0, 0, 0, 0, 0, 0)); // player 1 passes with an empty take
+
+        let payment = tokens(0, 0, 0, 4, 0, 0);
+        let summary = arena.purchase_card(card.id(), payment);
+
+        assert_eq!(summary.player, 0);
+        assert_eq!(summary.player_deltas[0].point_gain, 1);
+        assert_eq!(
+            summary.player_deltas[0].new_developments,
+            Cost {
+                ruby: 0,
+                emerald: 0,
+                sapphire: 1,
+                diamond: 0,
+                onyx: 0,
+            }
+        );
+        assert_eq!(summary.bank_delta.diamond, 4);
+    }
+
+    #[test]
+    fn test_undo_reserve_restores_card_to_its_original_board_slot() {
+        let mut arena = Arena::new(2);
+        let board_before = arena.board().to_vec();
+        let reserved_card = board_before[1];
+
+        arena.reserve_card(reserved_card);
+        arena.undo();
+
+        assert_eq!(arena.board(), board_before);
+    }
+
+    #[test]
+    fn test_undo_purchase_restores_card_to_its_original_board_slot() {
+        let mut arena = Arena::new(2);
+        let board_before = arena.board().to_vec();
+        let card = find_card(board_before[2]);
+        arena.take_tokens(card.cost().to_tokens());
+        arena.take_tokens(tokens(0, 0, 0, 0, 0, 0)); // player 1 passes with an empty take
+        let board_after_take = arena.board().to_vec();
+
+        arena.purchase_card(card.id(), card.cost().to_tokens());
+        arena.undo();
+
+        assert_eq!(arena.board(), board_after_take);
+    }
+
+    #[test]
+    fn test_standard_config_does_not_panic_for_more_players_than_nobles_exist() {
+        let config = GameConfig::standard(20);
+        assert_eq!(
+            config.validate(),
+            Err(GameConfigError::NotEnoughNobles {
+                have: Noble::all().len(),
+                need: 21,
+            })
+        );
+    }
+
+    #[test]
+    fn test_game_config_validate_rejects_unknown_noble_id() {
+        let mut config = GameConfig::standard(2);
+        let bogus_id = NobleId(9999);
+        config.nobles[0] = bogus_id;
+        assert_eq!(config.validate(), Err(GameConfigError::UnknownNoble(bogus_id)));
+    }
+
+    #[test]
+    fn test_swap_nobles_rejects_unknown_noble_id() {
+        let mut arena = Arena::new(2);
+        let bogus_id = NobleId(9999);
+        assert_eq!(
+            arena.swap_nobles(vec![bogus_id, NobleId(0), NobleId(1)]),
+            Err(GameConfigError::UnknownNoble(bogus_id))
+        );
+    }
+}
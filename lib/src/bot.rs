@@ -0,0 +1,423 @@
+use crate::arena::Arena;
+use crate::card::CardId;
+use crate::game_logic::{eligible_nobles, find_card, MAX_RESERVED, WINNING_SCORE};
+use crate::gem_type::GemType;
+use crate::player::PlayerPublicInfo;
+use crate::rng::Rng;
+use crate::token::Tokens;
+use crate::JSONable;
+use serde::{Deserialize, Serialize};
+
+/// A legal action a player may take on their turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Move {
+    TakeTokens(Tokens),
+    Reserve(CardId),
+    BlindReserve,
+    Purchase { card: CardId, payment: Tokens },
+}
+
+/// Everything a `Strategy` is allowed to see: the acting player's own
+/// public info, every opponent's public info, the face-up board, the
+/// bank, and the full set of legal moves to choose from. Players appear
+/// only through `PlayerPublicInfo`: gem counts, developments, points and
+/// how many cards they have reserved, but not which cards those are.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameView {
+    pub viewer: usize,
+    pub own: PlayerPublicInfo,
+    pub opponents: Vec<PlayerPublicInfo>,
+    pub board: Vec<CardId>,
+    pub bank: Tokens,
+    pub legal_moves: Vec<Move>,
+}
+
+impl JSONable for GameView {}
+
+/// A pluggable policy for choosing moves. Strategies are stateful (e.g. to
+/// hold their own RNG) so `choose_move` takes `&mut self`.
+pub trait Strategy {
+    fn choose_move(&mut self, view: &GameView) -> Move;
+}
+
+/// Picks uniformly at random among the legal moves.
+pub struct RandomStrategy {
+    rng: Rng,
+}
+
+impl RandomStrategy {
+    pub fn new(seed: u64) -> RandomStrategy {
+        RandomStrategy { rng: Rng::new(seed) }
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn choose_move(&mut self, view: &GameView) -> Move {
+        let index = self.rng.gen_range(view.legal_moves.len());
+        view.legal_moves[index].clone()
+    }
+}
+
+/// Picks the move that scores the most points this turn, breaking ties by
+/// preferring to end the turn with more total developments.
+pub struct GreedyStrategy;
+
+impl Strategy for GreedyStrategy {
+    fn choose_move(&mut self, view: &GameView) -> Move {
+        view.legal_moves
+            .iter()
+            .max_by_key(|mv| move_value(view, mv))
+            .cloned()
+            .expect("legal_moves should never be empty when it is a player's turn")
+    }
+}
+
+fn move_value(view: &GameView, mv: &Move) -> (u8, u32) {
+    match mv {
+        Move::Purchase { card, .. } => {
+            let card = find_card(*card);
+            (card.points(), view.own.developments.total() + 1)
+        }
+        _ => (0, view.own.developments.total()),
+    }
+}
+
+/// Every legal move for the arena's current player: affordable purchases
+/// (from the board or their own reserved cards, via `payment_options_for`),
+/// every legal token-take combination the bank still supports, and
+/// reservations (board or blind), if they have room.
+pub fn legal_moves(arena: &Arena) -> Vec<Move> {
+    let player = &arena.players()[arena.current_player()];
+    let mut moves = Vec::new();
+
+    let purchasable: Vec<CardId> = arena
+        .board()
+        .iter()
+        .chain(player.all_reserved().iter())
+        .copied()
+        .collect();
+    for card_id in purchasable {
+        let card = find_card(card_id);
+        if let Some(payments) = player.payment_options_for(card) {
+            for payment in payments {
+                moves.push(Move::Purchase {
+                    card: card_id,
+                    payment,
+                });
+            }
+        }
+    }
+
+    if player.num_reserved() < MAX_RESERVED {
+        for &card_id in arena.board() {
+            moves.push(Move::Reserve(card_id));
+        }
+        if !arena.deck().is_empty() {
+            moves.push(Move::BlindReserve);
+        }
+    }
+
+    moves.extend(legal_token_takes(arena.bank()).into_iter().map(Move::TakeTokens));
+
+    moves
+}
+
+/// All legal ways to take tokens from the bank on a turn: two of one color
+/// (only if at least four remain) or one each of three distinct colors
+/// (each still available).
+///
+/// Two real-table rules aren't modeled here: the 10-token hand limit (no
+/// `Move` ever triggers a mandatory `Discard`, so a bot's hand can grow past
+/// ten) and the reduced end-game take when fewer than three colors remain in
+/// the bank (real Splendor lets a player take however many distinct colors
+/// are left; this only ever offers the full three-distinct combination).
+/// Both are accepted simplifications for headless simulation, not bugs.
+fn legal_token_takes(bank: &Tokens) -> Vec<Tokens> {
+    let colors = GemType::all();
+    let mut combos = Vec::new();
+
+    for &color in &colors {
+        if bank[color] >= 4 {
+            let mut tokens = Tokens::empty();
+            tokens[color] = 2;
+            combos.push(tokens);
+        }
+    }
+
+    for i in 0..colors.len() {
+        for j in (i + 1)..colors.len() {
+            for k in (j + 1)..colors.len() {
+                if bank[colors[i]] > 0 && bank[colors[j]] > 0 && bank[colors[k]] > 0 {
+                    let mut tokens = Tokens::empty();
+                    tokens[colors[i]] = 1;
+                    tokens[colors[j]] = 1;
+                    tokens[colors[k]] = 1;
+                    combos.push(tokens);
+                }
+            }
+        }
+    }
+
+    combos
+}
+
+/// A snapshot of `arena` as seen by `viewer`. `legal_moves` is only ever
+/// populated for the player whose turn it actually is; a non-acting viewer
+/// (e.g. a spectator watching an opponent) gets an empty list rather than
+/// the current player's moves mislabeled as their own.
+pub fn to_view(arena: &Arena, viewer: usize) -> GameView {
+    let moves = if viewer == arena.current_player() {
+        legal_moves(arena)
+    } else {
+        Vec::new()
+    };
+    GameView {
+        viewer,
+        own: arena.players()[viewer].to_public(),
+        opponents: arena
+            .players()
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != viewer)
+            .map(|(_, p)| p.to_public())
+            .collect(),
+        board: arena.board().to_vec(),
+        bank: *arena.bank(),
+        legal_moves: moves,
+    }
+}
+
+/// If `player`'s developments now satisfy any noble still in play, awards
+/// them the first one eligible. Real Splendor lets the player choose among
+/// several eligible nobles; the headless simulation just needs a
+/// deterministic pick so `noble_points` isn't always zero for balance
+/// testing.
+fn claim_eligible_noble(arena: &mut Arena, player: usize) {
+    let developments = *arena.players()[player].developments();
+    if let Some(noble) = eligible_nobles(arena.nobles(), &developments).first() {
+        arena.claim_noble(player, noble.id());
+    }
+}
+
+fn apply_move(arena: &mut Arena, mv: Move) {
+    match mv {
+        Move::TakeTokens(tokens) => {
+            arena.take_tokens(tokens);
+        }
+        Move::Reserve(card_id) => {
+            arena.reserve_card(card_id);
+        }
+        Move::BlindReserve => {
+            arena.blind_reserve_card();
+        }
+        Move::Purchase { card, payment } => {
+            arena.purchase_card(card, payment);
+        }
+    }
+}
+
+/// One player's final score, broken down by source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerPointsBreakdown {
+    pub total: u8,
+    pub noble_points: u8,
+}
+
+/// The outcome of a `simulate`d game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameResult {
+    pub winner: Option<usize>,
+    pub turn_count: u32,
+    pub final_points: Vec<PlayerPointsBreakdown>,
+}
+
+impl JSONable for GameResult {}
+
+/// A safety valve against strategies that stall out without ever reaching
+/// the winning score.
+const MAX_TURNS: u32 = 500;
+
+/// Plays a headless game to completion: each turn, the acting player's
+/// `Strategy` picks a move from its `GameView`, which is applied to the
+/// arena, until some player reaches `WINNING_SCORE` or `MAX_TURNS` is hit.
+/// `seed` shuffles the starting board and deck, so the same seed and
+/// strategies always produce the same game.
+///
+/// Like `legal_token_takes`, this doesn't model the 10-token hand limit, so
+/// strategies are never forced to `Discard`. It also doesn't model the
+/// reduced end-game token take, which combined with a bank that has been
+/// drawn down can leave `legal_moves` empty with no player having won; when
+/// that happens the loop just stops and `winner` is `None`, the same as
+/// hitting `MAX_TURNS` without anyone reaching the winning score.
+pub fn simulate(mut strategies: Vec<Box<dyn Strategy>>, seed: u64) -> GameResult {
+    let mut arena = Arena::new_seeded(strategies.len(), seed);
+    let mut turn_count = 0;
+
+    loop {
+        let current = arena.current_player();
+        let view = to_view(&arena, current);
+        if view.legal_moves.is_empty() {
+            break;
+        }
+
+        let mv = strategies[current].choose_move(&view);
+        apply_move(&mut arena, mv);
+        turn_count += 1;
+        claim_eligible_noble(&mut arena, current);
+
+        if arena.players()[current].points() >= WINNING_SCORE || turn_count >= MAX_TURNS {
+            break;
+        }
+    }
+
+    // `winner` is only `Some` if someone actually reached `WINNING_SCORE`;
+    // a game that stopped early (a dry bank with no legal moves) or ran out
+    // the clock at `MAX_TURNS` without anyone getting there is a stalemate,
+    // not a win for whoever happened to be ahead.
+    let winner = if arena.players().iter().any(|p| p.points() >= WINNING_SCORE) {
+        arena
+            .players()
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, p)| p.points())
+            .map(|(i, _)| i)
+    } else {
+        None
+    };
+
+    GameResult {
+        winner,
+        turn_count,
+        final_points: arena
+            .players()
+            .iter()
+            .map(|p| PlayerPointsBreakdown {
+                total: p.points(),
+                noble_points: p.noble_points(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+
+    fn empty_view(legal_moves: Vec<Move>) -> GameView {
+        GameView {
+            viewer: 0,
+            own: PlayerPublicInfo {
+                points: 0,
+                points_breakdown: crate::player::PointsBreakdown {
+                    card_points: 0,
+                    noble_points: 0,
+                },
+                num_reserved: 0,
+                developments: crate::card::Cost::zero(),
+                gems: Tokens::empty(),
+            },
+            opponents: Vec::new(),
+            board: Vec::new(),
+            bank: Tokens::empty(),
+            legal_moves,
+        }
+    }
+
+    #[test]
+    fn test_legal_token_takes_two_of_a_color_requires_at_least_four_in_bank() {
+        let mut bank = Tokens::empty();
+        let two_ruby = Tokens { ruby: 2, ..Tokens::empty() };
+
+        bank.ruby = 3;
+        assert!(!legal_token_takes(&bank).contains(&two_ruby));
+
+        bank.ruby = 4;
+        assert!(legal_token_takes(&bank).contains(&two_ruby));
+    }
+
+    #[test]
+    fn test_legal_token_takes_three_distinct_colors_each_available() {
+        let mut bank = Tokens::empty();
+        bank.ruby = 1;
+        bank.emerald = 1;
+        bank.sapphire = 1;
+
+        let combos = legal_token_takes(&bank);
+
+        assert_eq!(combos.len(), 1);
+        assert_eq!(
+            combos[0],
+            Tokens {
+                ruby: 1,
+                emerald: 1,
+                sapphire: 1,
+                ..Tokens::empty()
+            }
+        );
+    }
+
+    #[test]
+    fn test_legal_moves_stops_offering_reservations_at_max_reserved() {
+        let mut arena = Arena::new(2);
+        for _ in 0..MAX_RESERVED {
+            let card_id = arena.board()[0];
+            arena.reserve_card(card_id);
+            arena.take_tokens(Tokens::empty()); // player 1 passes
+        }
+
+        let moves = legal_moves(&arena);
+
+        assert!(!moves
+            .iter()
+            .any(|mv| matches!(mv, Move::Reserve(_) | Move::BlindReserve)));
+    }
+
+    #[test]
+    fn test_legal_moves_omits_blind_reserve_once_the_deck_is_empty() {
+        // Four players gives each one room (MAX_RESERVED == 3) to reserve
+        // enough cards between them to drain the ten-card deck without any
+        // single player going past their own reservation cap.
+        let mut arena = Arena::new(4);
+        while !arena.deck().is_empty() {
+            let card_id = arena.board()[0];
+            arena.reserve_card(card_id);
+        }
+
+        let moves = legal_moves(&arena);
+
+        assert!(!moves.iter().any(|mv| matches!(mv, Move::BlindReserve)));
+    }
+
+    #[test]
+    fn test_greedy_strategy_prefers_the_higher_point_purchase() {
+        let low = Move::Purchase { card: Card::all()[0].id(), payment: Tokens::empty() };
+        let high = Move::Purchase { card: Card::all()[3].id(), payment: Tokens::empty() };
+        let view = empty_view(vec![low, high.clone()]);
+
+        assert_eq!(GreedyStrategy.choose_move(&view), high);
+    }
+
+    #[test]
+    fn test_greedy_strategy_breaks_a_point_tie_by_preferring_to_purchase() {
+        let pass = Move::TakeTokens(Tokens::empty());
+        let zero_point_purchase = Move::Purchase { card: Card::all()[0].id(), payment: Tokens::empty() };
+        let view = empty_view(vec![pass, zero_point_purchase.clone()]);
+
+        assert_eq!(GreedyStrategy.choose_move(&view), zero_point_purchase);
+    }
+
+    #[test]
+    fn test_simulate_random_vs_random_terminates_with_a_winner() {
+        let strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(RandomStrategy::new(19207)),
+            Box::new(RandomStrategy::new(1019190)),
+        ];
+
+        let result = simulate(strategies, 19207);
+
+        assert_eq!(result.winner, Some(0));
+        assert_eq!(result.final_points[0].total, WINNING_SCORE);
+        assert!(result.turn_count <= MAX_TURNS);
+    }
+}
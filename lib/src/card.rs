@@ -0,0 +1,145 @@
+use crate::gem_type::GemType;
+use crate::token::Tokens;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a development card. Stable across a game so it can be used as
+/// a key in `Player::reserved`/`blind_reserved` and referenced from a
+/// `GameEvent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CardId(pub u32);
+
+/// A card's price, expressed purely in colored gems. Nothing is ever priced
+/// in `Gold`, which is why this is a distinct (smaller) type from `Tokens`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cost {
+    pub ruby: u8,
+    pub emerald: u8,
+    pub sapphire: u8,
+    pub diamond: u8,
+    pub onyx: u8,
+}
+
+impl Cost {
+    pub fn zero() -> Cost {
+        Cost {
+            ruby: 0,
+            emerald: 0,
+            sapphire: 0,
+            diamond: 0,
+            onyx: 0,
+        }
+    }
+
+    /// Reduces each colored amount by the matching development count,
+    /// never going below zero for a single color.
+    pub fn discounted_with(&self, developments: &Tokens) -> Cost {
+        Cost {
+            ruby: self.ruby.saturating_sub(developments.ruby),
+            emerald: self.emerald.saturating_sub(developments.emerald),
+            sapphire: self.sapphire.saturating_sub(developments.sapphire),
+            diamond: self.diamond.saturating_sub(developments.diamond),
+            onyx: self.onyx.saturating_sub(developments.onyx),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.ruby as u32
+            + self.emerald as u32
+            + self.sapphire as u32
+            + self.diamond as u32
+            + self.onyx as u32
+    }
+
+    pub fn to_tokens(&self) -> Tokens {
+        Tokens {
+            ruby: self.ruby,
+            emerald: self.emerald,
+            sapphire: self.sapphire,
+            diamond: self.diamond,
+            onyx: self.onyx,
+            gold: 0,
+        }
+    }
+
+    /// Projects a `Tokens` bag down onto a `Cost` by dropping its `gold`
+    /// count. Used to report a player's developments (which never include
+    /// gold) in the same shape as a card price.
+    pub fn from_tokens(tokens: &Tokens) -> Cost {
+        Cost {
+            ruby: tokens.ruby,
+            emerald: tokens.emerald,
+            sapphire: tokens.sapphire,
+            diamond: tokens.diamond,
+            onyx: tokens.onyx,
+        }
+    }
+}
+
+/// A development card: produces `gem` once purchased, is worth `points`,
+/// and costs `cost` (before discounts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Card {
+    id: CardId,
+    level: u8,
+    gem: GemType,
+    points: u8,
+    cost: Cost,
+}
+
+impl Card {
+    pub fn id(&self) -> CardId {
+        self.id
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn gem(&self) -> GemType {
+        self.gem
+    }
+
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn cost(&self) -> Cost {
+        self.cost
+    }
+
+    /// Every card in the game, in a stable order keyed by `CardId`.
+    pub fn all() -> &'static [Card] {
+        &CARDS
+    }
+}
+
+const fn cost(ruby: u8, emerald: u8, sapphire: u8, diamond: u8, onyx: u8) -> Cost {
+    Cost {
+        ruby,
+        emerald,
+        sapphire,
+        diamond,
+        onyx,
+    }
+}
+
+const CARDS: [Card; 18] = [
+    Card { id: CardId(0), level: 1, gem: GemType::Ruby, points: 0, cost: cost(0, 1, 1, 1, 0) },
+    Card { id: CardId(1), level: 1, gem: GemType::Ruby, points: 0, cost: cost(0, 2, 1, 0, 0) },
+    Card { id: CardId(2), level: 1, gem: GemType::Emerald, points: 0, cost: cost(1, 0, 1, 1, 1) },
+    Card { id: CardId(3), level: 1, gem: GemType::Sapphire, points: 1, cost: cost(0, 0, 0, 4, 0) },
+    Card { id: CardId(4), level: 1, gem: GemType::Diamond, points: 0, cost: cost(1, 2, 0, 0, 0) },
+    Card { id: CardId(5), level: 1, gem: GemType::Onyx, points: 0, cost: cost(2, 0, 0, 1, 0) },
+    Card { id: CardId(6), level: 1, gem: GemType::Ruby, points: 0, cost: cost(0, 3, 0, 0, 0) },
+    Card { id: CardId(7), level: 1, gem: GemType::Emerald, points: 1, cost: cost(0, 0, 4, 0, 0) },
+    Card { id: CardId(8), level: 2, gem: GemType::Sapphire, points: 2, cost: cost(0, 2, 0, 3, 0) },
+    Card { id: CardId(9), level: 2, gem: GemType::Diamond, points: 2, cost: cost(3, 0, 0, 0, 2) },
+    Card { id: CardId(10), level: 2, gem: GemType::Onyx, points: 3, cost: cost(0, 0, 0, 6, 0) },
+    Card { id: CardId(11), level: 2, gem: GemType::Ruby, points: 1, cost: cost(0, 3, 2, 2, 0) },
+    Card { id: CardId(12), level: 2, gem: GemType::Emerald, points: 2, cost: cost(2, 0, 2, 0, 3) },
+    Card { id: CardId(13), level: 2, gem: GemType::Sapphire, points: 1, cost: cost(0, 2, 0, 0, 2) },
+    Card { id: CardId(14), level: 3, gem: GemType::Diamond, points: 4, cost: cost(0, 0, 0, 7, 0) },
+    Card { id: CardId(15), level: 3, gem: GemType::Onyx, points: 5, cost: cost(3, 3, 3, 0, 3) },
+    Card { id: CardId(16), level: 3, gem: GemType::Ruby, points: 3, cost: cost(3, 0, 3, 3, 3) },
+    Card { id: CardId(17), level: 3, gem: GemType::Emerald, points: 4, cost: cost(0, 0, 0, 3, 6) },
+];
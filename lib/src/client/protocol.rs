@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Messages a connected client can send to the tournament server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ClientMessage {
+    Join { name: String },
+    Leave,
+}
+
+/// Messages the tournament server sends back to a connected client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ServerMessage {
+    Joined { player: usize },
+    Error { reason: String },
+}
@@ -0,0 +1 @@
+pub mod gem_type;
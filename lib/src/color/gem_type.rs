@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// The six token/resource colors used throughout the game: five gem colors
+/// that cards and nobles require, plus the wild `Gold` token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GemType {
+    Ruby,
+    Emerald,
+    Sapphire,
+    Diamond,
+    Onyx,
+    Gold,
+}
+
+impl GemType {
+    /// The five colored gem types that card and noble costs are expressed in.
+    /// `Gold` is a wild token and is never itself part of a cost, so it is
+    /// excluded here.
+    pub fn all() -> [GemType; 5] {
+        [
+            GemType::Ruby,
+            GemType::Emerald,
+            GemType::Sapphire,
+            GemType::Diamond,
+            GemType::Onyx,
+        ]
+    }
+}
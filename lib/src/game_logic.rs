@@ -0,0 +1,41 @@
+use crate::card::{Card, CardId};
+use crate::gem_type::GemType;
+use crate::nobles::{Noble, NobleId};
+use crate::token::Tokens;
+
+/// A player may hold at most this many reserved cards (blind or otherwise)
+/// at one time.
+pub const MAX_RESERVED: usize = 3;
+
+/// The number of points (from cards and nobles) needed to end the game.
+pub const WINNING_SCORE: u8 = 15;
+
+/// Looks up a card by id. Cards are immutable and globally known, so this
+/// is just a scan over `Card::all()` rather than a per-arena table.
+pub fn find_card(id: CardId) -> &'static Card {
+    Card::all()
+        .iter()
+        .find(|card| card.id() == id)
+        .expect("card id should refer to a known card")
+}
+
+/// Looks up a noble by id. Nobles are immutable and globally known, so this
+/// is just a scan over `Noble::all()` rather than a per-arena table.
+pub fn find_noble(id: NobleId) -> &'static Noble {
+    Noble::all()
+        .iter()
+        .find(|noble| noble.id() == id)
+        .expect("noble id should refer to a known noble")
+}
+
+/// Which of `nobles` a player's current developments satisfy.
+pub fn eligible_nobles<'a>(nobles: &'a [Noble], developments: &Tokens) -> Vec<&'a Noble> {
+    nobles
+        .iter()
+        .filter(|noble| {
+            GemType::all()
+                .iter()
+                .all(|&color| developments[color] >= noble.requirement()[color])
+        })
+        .collect()
+}
@@ -1,6 +1,7 @@
 #![allow(unused)]
 
 pub mod arena;
+pub mod bot;
 pub mod card;
 pub mod color;
 pub mod game_logic;
@@ -8,8 +9,10 @@ pub mod nobles;
 pub mod player;
 pub mod token;
 pub mod client;
+mod rng;
 
 pub use crate::arena::*;
+pub use crate::bot::*;
 pub use crate::card::*;
 pub use crate::color::*;
 pub use crate::game_logic::*;
@@ -0,0 +1,54 @@
+use crate::token::Tokens;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a noble tile. Stable across a game so a `GameConfig` can pin
+/// down exactly which nobles are drawn into play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NobleId(pub u32);
+
+/// A noble tile: awards points to the first player whose `developments`
+/// meet `requirement`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Noble {
+    id: NobleId,
+    requirement: Tokens,
+}
+
+impl Noble {
+    pub fn id(&self) -> NobleId {
+        self.id
+    }
+
+    pub fn requirement(&self) -> &Tokens {
+        &self.requirement
+    }
+
+    /// Every noble tile in the game, in a stable order keyed by `NobleId`.
+    pub fn all() -> &'static [Noble] {
+        &NOBLES
+    }
+}
+
+const fn requirement(ruby: u8, emerald: u8, sapphire: u8, diamond: u8, onyx: u8) -> Tokens {
+    Tokens {
+        ruby,
+        emerald,
+        sapphire,
+        diamond,
+        onyx,
+        gold: 0,
+    }
+}
+
+const NOBLES: [Noble; 10] = [
+    Noble { id: NobleId(0), requirement: requirement(4, 4, 0, 0, 0) },
+    Noble { id: NobleId(1), requirement: requirement(0, 4, 4, 0, 0) },
+    Noble { id: NobleId(2), requirement: requirement(0, 0, 4, 4, 0) },
+    Noble { id: NobleId(3), requirement: requirement(0, 0, 0, 4, 4) },
+    Noble { id: NobleId(4), requirement: requirement(4, 0, 0, 0, 4) },
+    Noble { id: NobleId(5), requirement: requirement(3, 3, 3, 0, 0) },
+    Noble { id: NobleId(6), requirement: requirement(0, 3, 3, 3, 0) },
+    Noble { id: NobleId(7), requirement: requirement(0, 0, 3, 3, 3) },
+    Noble { id: NobleId(8), requirement: requirement(3, 0, 0, 3, 3) },
+    Noble { id: NobleId(9), requirement: requirement(3, 3, 0, 0, 3) },
+];
@@ -3,12 +3,21 @@ use crate::gem_type::*;
 use crate::token::Tokens;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-
-use cached::proc_macro::cached;
+use std::ops::RangeInclusive;
+
+/// Where a player's `points` came from: developments versus nobles. `Player`
+/// already tracks `noble_points` separately, so this is just that split
+/// surfaced on the public view for spectators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointsBreakdown {
+    pub card_points: u8,
+    pub noble_points: u8,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerPublicInfo {
     pub points: u8,
+    pub points_breakdown: PointsBreakdown,
     pub num_reserved: usize,
     pub developments: Cost,
     pub gems: Tokens,
@@ -24,42 +33,36 @@ pub struct Player {
     blind_reserved: Vec<CardId>,
 }
 
-#[cached]
-fn token_match(cost: Tokens, gems: Tokens, running_payment: Tokens) -> HashSet<Tokens> {
-    if cost.total() == 0 {
-        return HashSet::from_iter(vec![running_payment]);
-    }
-    if gems.total() == 0 {
-        return HashSet::new();
-    }
+/// For one color, the range of gold tokens `k` that may stand in for that
+/// color's own tokens when paying `cost`, given the player holds `gems` of
+/// it: at least `cost - gems` (forced once the player falls short) and at
+/// most `cost` (paying the whole thing in gold, however wasteful). Colored
+/// tokens spent are always `cost - k`.
+fn gold_range(cost: u8, gems: u8) -> RangeInclusive<u8> {
+    cost.saturating_sub(gems)..=cost
+}
 
-    // Take one token that satisfies the cost or a wild token and recurse
-    let mut result = Vec::new();
+/// The per-color gold ranges for paying `cost` out of `gems`, or `None` if
+/// even spending the minimum forced gold on every color already exceeds
+/// the gold the player holds.
+fn payment_ranges(cost: &Tokens, gems: &Tokens) -> Option<Vec<(GemType, RangeInclusive<u8>)>> {
+    let mut ranges = Vec::new();
+    let mut min_gold_needed: u32 = 0;
     for color in GemType::all() {
-        if cost[color] > 0 {
-            let new_cost = cost - Tokens::one(color);
-
-            if gems[color] > 0 {
-                let new_gems = gems - Tokens::one(color);
-                result.extend(token_match(
-                    new_cost,
-                    new_gems,
-                    running_payment + Tokens::one(color),
-                ));
-            }
-
-            if gems[GemType::Gold] > 0 {
-                let new_gems = gems - Tokens::one(GemType::Gold);
-                result.extend(token_match(
-                    new_cost,
-                    new_gems,
-                    running_payment + Tokens::one(GemType::Gold),
-                ));
-            }
-        }
+        let range = gold_range(cost[color], gems[color]);
+        min_gold_needed += *range.start() as u32;
+        ranges.push((color, range));
+    }
+    if min_gold_needed > gems[GemType::Gold] as u32 {
+        return None;
     }
+    Some(ranges)
+}
 
-    HashSet::from_iter(result)
+impl Default for Player {
+    fn default() -> Player {
+        Player::new()
+    }
 }
 
 impl Player {
@@ -77,9 +80,13 @@ impl Player {
     pub fn to_public(&self) -> PlayerPublicInfo {
         PlayerPublicInfo {
             points: self.points,
+            points_breakdown: PointsBreakdown {
+                card_points: self.points - self.noble_points,
+                noble_points: self.noble_points,
+            },
             num_reserved: self.reserved.len(),
             developments: Cost::from_tokens(&self.developments),
-            gems: self.gems.clone(),
+            gems: self.gems,
         }
     }
 
@@ -99,6 +106,12 @@ impl Player {
         self.noble_points += 3;
     }
 
+    /// Reverses `add_noble_points`, for undoing a `ClaimNoble` event.
+    pub fn remove_noble_points(&mut self) {
+        self.points -= 3;
+        self.noble_points -= 3;
+    }
+
     /// Return the number of reserved cards in total
     pub fn num_reserved(&self) -> usize {
         self.reserved.len()
@@ -167,29 +180,121 @@ impl Player {
         self.blind_reserved.push(card_id);
     }
 
-    /// Returns the token spread that a player needs to afford
-    /// a given card.
+    /// Reverses `reserve_card`/`blind_reserve_card`, for undoing a `Reserve`
+    /// or `BlindReserve` event.
+    pub fn unreserve_card(&mut self, card_id: CardId) {
+        self.reserved.retain(|&x| x != card_id);
+        self.blind_reserved.retain(|&x| x != card_id);
+    }
+
+    /// Reverses `purchase_card`, for undoing a `Purchase` event: returns the
+    /// spent gems, removes the gained development, subtracts the earned
+    /// points, and restores the card to `reserved`/`blind_reserved` if it
+    /// had been reserved before the purchase.
+    pub fn unpurchase_card(
+        &mut self,
+        card: &Card,
+        payment: &Tokens,
+        was_reserved: bool,
+        was_blind_reserved: bool,
+    ) {
+        self.gems += *payment;
+        self.developments -= Tokens::one(card.gem());
+        self.points -= card.points();
+        if was_reserved {
+            self.reserved.push(card.id());
+        }
+        if was_blind_reserved {
+            self.blind_reserved.push(card.id());
+        }
+    }
+
+    /// Every distinct token spread the player could pay for `card` with,
+    /// after development discounts. Built color-by-color instead of
+    /// recursing token-by-token: for each color the gold spent on it can
+    /// range over `gold_range`, and a payment is exactly one choice per
+    /// color whose gold use sums to at most the player's gold.
     pub fn payment_options_for(&self, card: &Card) -> Option<HashSet<Tokens>> {
-        let cost = card.cost();
-        let cost = cost.discounted_with(&self.developments).to_tokens();
-        let mut total_deficit = 0;
-        for color in GemType::all() {
-            let deficit = cost[color] - self.gems[color];
-            if deficit > 0 {
-                total_deficit += deficit;
+        let cost = card.cost().discounted_with(&self.developments).to_tokens();
+        let gold_available = self.gems[GemType::Gold];
+        let ranges = payment_ranges(&cost, &self.gems)?;
+
+        let mut partials: Vec<(Tokens, u8)> = vec![(Tokens::empty(), 0)];
+        for (color, range) in &ranges {
+            let mut next = Vec::new();
+            for (tokens, gold_used) in &partials {
+                for k in range.clone() {
+                    let total_gold = gold_used + k;
+                    if total_gold > gold_available {
+                        break;
+                    }
+                    let mut tokens = *tokens;
+                    tokens[*color] = cost[*color] - k;
+                    tokens.gold = total_gold;
+                    next.push((tokens, total_gold));
+                }
             }
+            partials = next;
         }
 
-        // Cannot pay off deficit with wild tokens
-        if total_deficit > self.gems[GemType::Gold] {
+        if partials.is_empty() {
             return None;
         }
-        // Card is free!
-        let payments = token_match(cost, self.gems, Tokens::empty());
-        if payments.len() == 0 {
+        Some(partials.into_iter().map(|(tokens, _)| tokens).collect())
+    }
+
+    /// The number of distinct payments `payment_options_for` would return,
+    /// computed with a gold-use DP instead of materializing every option.
+    pub fn count_payment_options(&self, card: &Card) -> usize {
+        let cost = card.cost().discounted_with(&self.developments).to_tokens();
+        let gold_available = self.gems[GemType::Gold] as usize;
+        let Some(ranges) = payment_ranges(&cost, &self.gems) else {
+            return 0;
+        };
+
+        // ways[g] = number of ways to pay the colors processed so far while
+        // spending exactly g gold tokens in total.
+        let mut ways = vec![0u64; gold_available + 1];
+        ways[0] = 1;
+        for (_, range) in &ranges {
+            let mut next = vec![0u64; gold_available + 1];
+            for (spent_before, &count) in ways.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+                for k in range.clone() {
+                    let total = spent_before + k as usize;
+                    if total > gold_available {
+                        break;
+                    }
+                    next[total] += count;
+                }
+            }
+            ways = next;
+        }
+        ways.iter().sum::<u64>() as usize
+    }
+
+    /// The payment that spends as little gold as possible: the forced
+    /// minimum `k` on every color. Useful for bots that want to hold onto
+    /// gold for cards they can't otherwise afford.
+    pub fn cheapest_gold_payment(&self, card: &Card) -> Option<Tokens> {
+        let cost = card.cost().discounted_with(&self.developments).to_tokens();
+        let gold_available = self.gems[GemType::Gold];
+        let ranges = payment_ranges(&cost, &self.gems)?;
+
+        let mut tokens = Tokens::empty();
+        let mut gold_used = 0;
+        for (color, range) in &ranges {
+            let k = *range.start();
+            tokens[*color] = cost[*color] - k;
+            gold_used += k;
+        }
+        if gold_used > gold_available {
             return None;
         }
-        Some(payments)
+        tokens.gold = gold_used;
+        Some(tokens)
     }
 }
 
@@ -200,6 +305,18 @@ mod tests {
     use crate::gem_type::GemType;
     use crate::token::Tokens;
 
+    #[test]
+    fn test_to_public_reports_points_breakdown() {
+        let mut player = Player::new();
+        player.add_points(2);
+        player.add_noble_points();
+
+        let public = player.to_public();
+        assert_eq!(public.points, 5);
+        assert_eq!(public.points_breakdown.card_points, 2);
+        assert_eq!(public.points_breakdown.noble_points, 3);
+    }
+
     /// Testing strategy:
     ///     payment_to_afford:
     ///         - has 0, 1, >1 wild (gold) tokens
@@ -448,4 +565,62 @@ mod tests {
 
         assert_eq!(payment.len(), 5);
     }
+
+    #[test]
+    fn test_count_payment_options_matches_enumeration() {
+        let mut player = Player::new();
+        player.add_gems(Tokens::one(GemType::Emerald));
+        player.add_gems(Tokens::one(GemType::Emerald));
+        player.add_gems(Tokens::one(GemType::Onyx));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Gold));
+
+        let card = Card::all()[13];
+        assert_eq!(player.count_payment_options(&card), 5);
+    }
+
+    #[test]
+    fn test_count_payment_options_unaffordable_is_zero() {
+        let mut player = Player::new();
+        player.add_gems(Tokens::one(GemType::Ruby));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Onyx));
+
+        let card = Card::all()[4];
+        assert_eq!(player.count_payment_options(&card), 0);
+    }
+
+    #[test]
+    fn test_cheapest_gold_payment_spends_no_gold_on_a_fully_discounted_card() {
+        let mut player = Player::new();
+        player.add_gems(Tokens::one(GemType::Ruby));
+        player.add_gems(Tokens::one(GemType::Ruby));
+        player.add_gems(Tokens::one(GemType::Onyx));
+        player.add_gems(Tokens::one(GemType::Onyx));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Emerald));
+
+        player.add_development(GemType::Ruby);
+        player.add_development(GemType::Emerald);
+        player.add_development(GemType::Emerald);
+        player.add_development(GemType::Emerald);
+        player.add_development(GemType::Emerald);
+
+        let card = Card::all()[6];
+        let payment = player.cheapest_gold_payment(&card).unwrap();
+        assert_eq!(payment.gold, 0);
+    }
+
+    #[test]
+    fn test_cheapest_gold_payment_none_when_unaffordable() {
+        let mut player = Player::new();
+        player.add_gems(Tokens::one(GemType::Ruby));
+        player.add_gems(Tokens::one(GemType::Gold));
+        player.add_gems(Tokens::one(GemType::Onyx));
+
+        let card = Card::all()[4];
+        assert_eq!(player.cheapest_gold_payment(&card), None);
+    }
 }
@@ -0,0 +1,144 @@
+use crate::gem_type::GemType;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+
+/// A bag of tokens: one count per gem color plus `gold` (the wild token).
+/// Used both for a player's held gems and for card/noble costs once they
+/// have been converted away from `Cost` (which has no `gold` field, since
+/// nothing is ever priced in gold).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Tokens {
+    pub ruby: u8,
+    pub emerald: u8,
+    pub sapphire: u8,
+    pub diamond: u8,
+    pub onyx: u8,
+    pub gold: u8,
+}
+
+impl Tokens {
+    pub fn empty() -> Tokens {
+        Tokens::default()
+    }
+
+    /// A bag containing exactly one token of `color`.
+    pub fn one(color: GemType) -> Tokens {
+        let mut tokens = Tokens::empty();
+        tokens[color] = 1;
+        tokens
+    }
+
+    pub fn total(&self) -> u32 {
+        self.ruby as u32
+            + self.emerald as u32
+            + self.sapphire as u32
+            + self.diamond as u32
+            + self.onyx as u32
+            + self.gold as u32
+    }
+
+    /// A bag of tokens is legal so long as it was never driven negative;
+    /// since counts are unsigned this amounts to a no-op guard that callers
+    /// use as a cheap sanity check after subtracting.
+    pub fn legal(&self) -> bool {
+        true
+    }
+}
+
+impl Index<GemType> for Tokens {
+    type Output = u8;
+
+    fn index(&self, color: GemType) -> &u8 {
+        match color {
+            GemType::Ruby => &self.ruby,
+            GemType::Emerald => &self.emerald,
+            GemType::Sapphire => &self.sapphire,
+            GemType::Diamond => &self.diamond,
+            GemType::Onyx => &self.onyx,
+            GemType::Gold => &self.gold,
+        }
+    }
+}
+
+impl IndexMut<GemType> for Tokens {
+    fn index_mut(&mut self, color: GemType) -> &mut u8 {
+        match color {
+            GemType::Ruby => &mut self.ruby,
+            GemType::Emerald => &mut self.emerald,
+            GemType::Sapphire => &mut self.sapphire,
+            GemType::Diamond => &mut self.diamond,
+            GemType::Onyx => &mut self.onyx,
+            GemType::Gold => &mut self.gold,
+        }
+    }
+}
+
+impl Add for Tokens {
+    type Output = Tokens;
+
+    fn add(self, rhs: Tokens) -> Tokens {
+        Tokens {
+            ruby: self.ruby + rhs.ruby,
+            emerald: self.emerald + rhs.emerald,
+            sapphire: self.sapphire + rhs.sapphire,
+            diamond: self.diamond + rhs.diamond,
+            onyx: self.onyx + rhs.onyx,
+            gold: self.gold + rhs.gold,
+        }
+    }
+}
+
+impl AddAssign for Tokens {
+    fn add_assign(&mut self, rhs: Tokens) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Tokens {
+    type Output = Tokens;
+
+    fn sub(self, rhs: Tokens) -> Tokens {
+        Tokens {
+            ruby: self.ruby - rhs.ruby,
+            emerald: self.emerald - rhs.emerald,
+            sapphire: self.sapphire - rhs.sapphire,
+            diamond: self.diamond - rhs.diamond,
+            onyx: self.onyx - rhs.onyx,
+            gold: self.gold - rhs.gold,
+        }
+    }
+}
+
+impl SubAssign for Tokens {
+    fn sub_assign(&mut self, rhs: Tokens) {
+        *self = *self - rhs;
+    }
+}
+
+/// The signed, per-color change between two `Tokens` snapshots. Unlike
+/// `Tokens` itself this can go negative (a player spending gems, a bank
+/// paying out), which is why it is a separate type rather than reusing
+/// `Tokens` with wraparound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TokenDelta {
+    pub ruby: i8,
+    pub emerald: i8,
+    pub sapphire: i8,
+    pub diamond: i8,
+    pub onyx: i8,
+    pub gold: i8,
+}
+
+impl TokenDelta {
+    /// The change from `before` to `after`, one color at a time.
+    pub fn from_before_after(before: &Tokens, after: &Tokens) -> TokenDelta {
+        TokenDelta {
+            ruby: after.ruby as i8 - before.ruby as i8,
+            emerald: after.emerald as i8 - before.emerald as i8,
+            sapphire: after.sapphire as i8 - before.sapphire as i8,
+            diamond: after.diamond as i8 - before.diamond as i8,
+            onyx: after.onyx as i8 - before.onyx as i8,
+            gold: after.gold as i8 - before.gold as i8,
+        }
+    }
+}